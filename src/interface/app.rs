@@ -1,42 +1,357 @@
+use super::keymap::Keymap;
+use super::log_buffer::{self, LogBuffer};
 use crate::{
     models::{TraktShow, UserStatusSeason, UserStatusShow, TraktSeason},
     sources::DataManager,
     trakt::{t_api, t_db},
 };
 use log::*;
-use ratatui::widgets::{ScrollbarState, TableState};
+use ratatui::widgets::ScrollbarState;
 use reqwest::Client;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use tui_input::Input;
 
+/// How long to wait after the last keystroke before sending a query to
+/// `DataManager`, so fast typing doesn't spam it with one query per key.
+const QUERY_DEBOUNCE: Duration = Duration::from_millis(150);
+
 /// Different modes for the app.
-#[derive(PartialEq, Eq, Debug, Default)]
+#[derive(PartialEq, Eq, Hash, Debug, Default)]
 pub enum AppMode {
     /// Various tasks to init the app (e.g. data pull + insert)
     #[default]
     Initializing,
-    /// List of all the shows we find (from IMDB dataset / loaded from DB)
+    /// List of all the shows we find (from IMDB dataset / loaded from DB), now
+    /// rendered as an expandable show/season tree per column
     MainView,
-    /// somewhat of a todo state, i haven't impl'd searching yet
+    /// Incremental search over `all_shows`: keystrokes debounce into live
+    /// `DataManager` queries that populate the `ColumnFilter::Search` column
     Querying,
     /// Show keybindings
     #[allow(dead_code)]
     HelpWindow,
-    /// Detailed view of specific season
-    SeasonView,
     // Detailed view of a specific episode
     // not sure about this one yet
     // EpisodeView,
 }
 
-/// inner struct for detailed show views.
+/// The predicate a [`Column`] uses to decide which shows it displays.
+#[derive(Debug, Clone)]
+pub enum ColumnFilter {
+    Todo,
+    Unwatched,
+    Watched,
+    /// a saved search string, matched against title/IMDB id
+    Search(String),
+}
+
+impl ColumnFilter {
+    /// Whether `show` belongs in a column carrying this filter.
+    fn matches(&self, show: &TraktShow) -> bool {
+        match self {
+            ColumnFilter::Todo => show.user_status == UserStatusShow::Todo,
+            ColumnFilter::Unwatched => show.user_status == UserStatusShow::Unwatched,
+            ColumnFilter::Watched => show.user_status == UserStatusShow::Watched,
+            ColumnFilter::Search(query) => {
+                let query = query.to_lowercase();
+                show.title.to_lowercase().contains(&query)
+                    || show.imdb_id.to_lowercase().contains(&query)
+            }
+        }
+    }
+}
+
+/// What a [`TreeNode`] represents.
+#[derive(Debug)]
+pub enum TreeNodeKind {
+    Show(TraktShow),
+    Season(TraktSeason),
+    // Episode(TraktEpisode), // not modeled yet
+}
+
+/// A single row in the show/season tree, modeled on gobang's `DatabaseTreeItem`/
+/// `TreeItemInfo`: collapsing a parent hides its descendants by flipping their
+/// `visible` bit rather than removing them from the backing vec, so re-expanding
+/// doesn't require re-fetching anything already loaded.
+#[derive(Debug)]
+pub struct TreeNode {
+    pub kind: TreeNodeKind,
+    pub indent: u8,
+    pub visible: bool,
+    pub expanded: bool,
+}
+
+/// Flattened tree of shows and (once loaded) their seasons, replacing the old
+/// flat show table plus separate season-view mode.
 #[derive(Debug, Default)]
-pub struct AppShowView {
-    pub seasons: Vec<TraktSeason>,
+pub struct AppTree {
+    pub nodes: Vec<TreeNode>,
+    pub selected: Option<usize>,
+}
+
+impl AppTree {
+    fn from_shows(shows: Vec<TraktShow>) -> Self {
+        let nodes = shows
+            .into_iter()
+            .map(|show| TreeNode {
+                kind: TreeNodeKind::Show(show),
+                indent: 0,
+                visible: true,
+                expanded: false,
+            })
+            .collect();
+
+        let selected = if nodes.is_empty() { None } else { Some(0) };
+        AppTree { nodes, selected }
+    }
+
+    fn visible_indices(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.visible)
+            .map(|(i, _)| i)
+    }
+
+    pub fn move_down(&mut self, step: usize) {
+        let visible: Vec<usize> = self.visible_indices().collect();
+        let Some(pos) = self.current_visible_position(&visible) else {
+            self.selected = visible.first().copied();
+            return;
+        };
+        self.selected = visible
+            .get(std::cmp::min(pos.saturating_add(step), visible.len() - 1))
+            .copied();
+    }
+
+    pub fn move_up(&mut self, step: usize) {
+        let visible: Vec<usize> = self.visible_indices().collect();
+        let Some(pos) = self.current_visible_position(&visible) else {
+            self.selected = visible.last().copied();
+            return;
+        };
+        self.selected = visible.get(pos.saturating_sub(step)).copied();
+    }
+
+    fn current_visible_position(&self, visible: &[usize]) -> Option<usize> {
+        let selected = self.selected?;
+        visible.iter().position(|&i| i == selected)
+    }
+
+    /// Flips the `visible` bit of every descendant of `index` (nodes that follow
+    /// it with a greater indent), stopping at the first node back up to its level.
+    fn set_descendants_visible(&mut self, index: usize, visible: bool) {
+        let indent = self.nodes[index].indent;
+        for node in self.nodes.iter_mut().skip(index + 1) {
+            if node.indent <= indent {
+                break;
+            }
+            node.visible = visible;
+        }
+    }
+
+    /// Whether `index` already has child nodes loaded (used to decide whether
+    /// expanding requires a network fetch).
+    fn has_children(&self, index: usize) -> bool {
+        let indent = self.nodes[index].indent;
+        self.nodes
+            .get(index + 1)
+            .is_some_and(|node| node.indent > indent)
+    }
+
+    /// Replaces the direct children of `index` with freshly-loaded season nodes.
+    fn set_children(&mut self, index: usize, seasons: Vec<TraktSeason>) {
+        let parent_indent = self.nodes[index].indent;
+        let child_indent = parent_indent + 1;
+
+        let existing_children = self.nodes[index + 1..]
+            .iter()
+            .take_while(|node| node.indent > parent_indent)
+            .count();
+
+        let children = seasons.into_iter().map(|season| TreeNode {
+            kind: TreeNodeKind::Season(season),
+            indent: child_indent,
+            visible: true,
+            expanded: false,
+        });
+
+        self.nodes
+            .splice(index + 1..index + 1 + existing_children, children);
+    }
+
+    /// Toggles the expand/collapse state of `index`, revealing or hiding its
+    /// already-loaded direct children. Does not fetch anything.
+    fn toggle_expand(&mut self, index: usize) {
+        let expanding = !self.nodes[index].expanded;
+        self.nodes[index].expanded = expanding;
+
+        if expanding {
+            let indent = self.nodes[index].indent;
+            for node in self.nodes.iter_mut().skip(index + 1) {
+                if node.indent <= indent {
+                    break;
+                }
+                if node.indent == indent + 1 {
+                    node.visible = true;
+                }
+            }
+        } else {
+            self.set_descendants_visible(index, false);
+        }
+    }
+
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        self.selected.map(|i| &self.nodes[i])
+    }
+
+    /// Walks back from `index` to the nearest ancestor show node, returning its
+    /// imdb id. Used to resolve which show a season node belongs to.
+    fn ancestor_show_imdb_id(&self, index: usize) -> Option<&str> {
+        let indent = self.nodes[index].indent;
+        self.nodes[..index]
+            .iter()
+            .rev()
+            .find(|node| node.indent < indent)
+            .and_then(|node| match &node.kind {
+                TreeNodeKind::Show(show) => Some(show.imdb_id.as_str()),
+                TreeNodeKind::Season(_) => None,
+            })
+    }
+}
+
+/// One pane of the kanban-style workspace: a filtered show/season tree with its
+/// own selection and scroll state, rendered side by side with its sibling columns.
+#[derive(Debug)]
+pub struct Column {
+    pub name: String,
+    pub filter: ColumnFilter,
+    pub tree: AppTree,
+    pub scroll_state: ScrollbarState,
+}
+
+impl Column {
+    pub fn new(name: impl Into<String>, filter: ColumnFilter) -> Self {
+        Column {
+            name: name.into(),
+            filter,
+            tree: AppTree::default(),
+            scroll_state: ScrollbarState::default(),
+        }
+    }
+
+    /// Re-applies `filter` against the full show list, rebuilding this column's
+    /// tree. Loaded season nodes are discarded; they're cheap to re-fetch lazily.
+    fn refresh(&mut self, all_shows: &[TraktShow]) {
+        let shows: Vec<TraktShow> = all_shows
+            .iter()
+            .filter(|show| self.filter.matches(show))
+            .cloned()
+            .collect();
+
+        self.scroll_state = self.scroll_state.content_length(shows.len() as u16);
+        self.tree = AppTree::from_shows(shows);
+    }
+
+    pub fn next(&mut self, step: usize) {
+        self.tree.move_down(step);
+    }
+
+    pub fn prev(&mut self, step: usize) {
+        self.tree.move_up(step);
+    }
+}
+
+/// The kanban-style collection of [`Column`]s that replaces the single flat show
+/// list, with one column tracked as "focused" for navigation and key handling.
+#[derive(Debug)]
+pub struct Columns {
+    pub columns: Vec<Column>,
+    pub focused: usize,
+}
 
-    pub season_table_state: TableState,
-    // unimpl'd yet...
-    // pub episodes: Vec<>,
-    // pub episode_table_state: TableState,
+impl Columns {
+    /// The default Todo / Unwatched / Watched / Search column layout.
+    fn default_columns() -> Self {
+        Columns {
+            columns: vec![
+                Column::new("Todo", ColumnFilter::Todo),
+                Column::new("Unwatched", ColumnFilter::Unwatched),
+                Column::new("Watched", ColumnFilter::Watched),
+                Column::new("Search", ColumnFilter::Search(String::new())),
+            ],
+            focused: 0,
+        }
+    }
+
+    pub fn focused(&self) -> &Column {
+        &self.columns[self.focused]
+    }
+
+    pub fn focused_mut(&mut self) -> &mut Column {
+        &mut self.columns[self.focused]
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focused = (self.focused + 1) % self.columns.len();
+    }
+
+    pub fn focus_prev(&mut self) {
+        self.focused = (self.focused + self.columns.len() - 1) % self.columns.len();
+    }
+
+    /// Index of the column driven by live search, rather than by watch
+    /// status. Derived from `ColumnFilter::Search` each time instead of a
+    /// hardcoded position, so reordering or reconfiguring the columns can't
+    /// silently send query results into the wrong pane.
+    fn search_column_index(&self) -> usize {
+        self.columns
+            .iter()
+            .position(|column| matches!(column.filter, ColumnFilter::Search(_)))
+            .expect("default_columns always includes a Search column")
+    }
+
+    /// Re-runs every column's filter against the master show list, except the
+    /// search column: its contents come from the live `DataManager` query in
+    /// `App::tick`, so re-filtering it here would clobber whatever the last
+    /// query result placed there (and, with an empty query, would show the
+    /// entire catalog instead of nothing).
+    fn refresh_all(&mut self, all_shows: &[TraktShow]) {
+        let search_column = self.search_column_index();
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            if i == search_column {
+                continue;
+            }
+            column.refresh(all_shows);
+        }
+    }
+}
+
+/// A memoized `t_api::query_detailed` result, tagged with the show's DB
+/// revision at the time it was fetched (salsa-style: a cache entry is valid as
+/// long as the revision it was computed against is still current).
+#[derive(Debug, Clone)]
+struct CachedDetail {
+    revision: u64,
+    overview: String,
+    network: String,
+    aired_episodes: i32,
+    trakt_id: i32,
+    api_seasons: Vec<TraktSeason>,
+}
+
+/// Tracks an in-flight/pending incremental search so a newer keystroke can
+/// supersede an older query that's still debouncing or in flight.
+#[derive(Debug, Default)]
+struct SearchState {
+    query: String,
+    /// bumped on every dispatched query; results are only applied if their
+    /// generation still matches, so a superseded query's late result is dropped
+    generation: u64,
+    /// set on each keystroke, cleared once the debounced query is dispatched
+    dirty: bool,
+    last_edit: Option<Instant>,
 }
 
 /// Application.
@@ -55,12 +370,39 @@ pub struct App {
 
     /// used in main view
     pub input: Input,
-    pub table_state: TableState,
-    pub scroll_state: ScrollbarState,
-    pub shows: Vec<TraktShow>,
 
-    // used in season view
-    pub show_view: AppShowView,
+    /// master list backing every column; columns are filtered views over this
+    pub all_shows: Vec<TraktShow>,
+    /// kanban-style columns rendered side by side in the main view
+    pub columns: Columns,
+
+    /// formatted log lines, rendered by the toggleable log panel
+    pub log_buffer: LogBuffer,
+    /// whether the log panel is currently shown
+    pub show_log_panel: bool,
+    /// scroll position within the log panel, while it's shown
+    pub log_scroll: ScrollbarState,
+    log_scroll_offset: usize,
+
+    /// per-mode key bindings, loaded from the user's config (or the built-in
+    /// defaults) at startup
+    pub keymap: Keymap,
+    /// set by `handle_key_events` when expanding the selected node needs an
+    /// async network fetch; drained by the main loop
+    pending_expand: bool,
+
+    /// state for the incremental search driving the search column
+    search: SearchState,
+
+    /// set once the initial `all_shows` load has failed, so `tick` reports it
+    /// a single time instead of retrying (and re-logging) every tick
+    initial_load_failed: bool,
+
+    /// current DB revision per show, bumped whenever a mutation (via
+    /// `t_db::update_show`/`update_season`) could make a cached detail stale
+    show_revisions: HashMap<String, u64>,
+    /// memoized `query_detailed` results, keyed by imdb id
+    detail_cache: HashMap<String, CachedDetail>,
 }
 
 impl App {
@@ -70,6 +412,9 @@ impl App {
         // this task will receive a string query, and send back a TraktShow vec
         let data_manager = DataManager::init()?;
 
+        let log_buffer = LogBuffer::default();
+        log_buffer::install(log_buffer.clone())?;
+
         Ok(App {
             running: true,
             data_manager,
@@ -78,11 +423,23 @@ impl App {
 
             input: Input::default(),
             mode: AppMode::default(),
-            table_state: TableState::default(),
-            scroll_state: ScrollbarState::default(),
-            shows: Vec::new(),
+            all_shows: Vec::new(),
+            columns: Columns::default_columns(),
+
+            log_buffer,
+            show_log_panel: false,
+            log_scroll: ScrollbarState::default(),
+            log_scroll_offset: 0,
+
+            keymap: Keymap::load(),
+            pending_expand: false,
+
+            search: SearchState::default(),
+
+            initial_load_failed: false,
 
-            show_view: AppShowView::default(),
+            show_revisions: HashMap::new(),
+            detail_cache: HashMap::new(),
         })
     }
 
@@ -90,20 +447,47 @@ impl App {
     pub fn tick(&mut self) -> eyre::Result<()> {
         // WIP implementation of query from our data rows
         // (right now, just pull everything on boot)
-        if self.shows.is_empty() {
-            let items = self
-                .data_manager
-                .query(String::from("spurious"))
-                .ok_or_else(|| {
+        if self.all_shows.is_empty() && !self.initial_load_failed {
+            match self.data_manager.query(String::from("spurious")) {
+                Some(items) => {
+                    self.all_shows = items;
+                    self.columns.refresh_all(&self.all_shows);
+
+                    if self.mode == AppMode::Initializing {
+                        self.mode = AppMode::MainView;
+                    }
+                }
+                None => {
+                    // the data manager thread died; log it once and drop the
+                    // user back to the main view instead of tearing down the
+                    // TUI. Latched so this doesn't re-log on every tick while
+                    // `all_shows` stays empty.
                     error!("data manager thread panicked!");
-                    eyre::eyre!("data manager thread panicked!")
-                })?;
+                    self.initial_load_failed = true;
+                    self.mode = AppMode::MainView;
+                }
+            }
+        }
 
-            self.scroll_state = self.scroll_state.content_length(items.len() as u16);
-            self.shows = items;
+        // debounce: only fire a query once typing has paused for a bit
+        if self.search.dirty
+            && self
+                .search
+                .last_edit
+                .is_some_and(|edit| edit.elapsed() >= QUERY_DEBOUNCE)
+        {
+            self.dispatch_query();
+        }
 
-            if self.mode == AppMode::Initializing {
-                self.mode = AppMode::MainView;
+        if let Some((generation, items)) = self.data_manager.poll_query_result() {
+            // a newer query may have been dispatched since; drop stale results
+            // so they can't clobber what the user is now looking at
+            if generation == self.search.generation {
+                let search_column = self.columns.search_column_index();
+                let column = &mut self.columns.columns[search_column];
+                column.filter = ColumnFilter::Search(self.search.query.clone());
+                column.scroll_state = column.scroll_state.content_length(items.len() as u16);
+                column.tree = AppTree::from_shows(items);
             }
         }
 
@@ -115,115 +499,392 @@ impl App {
         self.running = false;
     }
 
-    pub fn next(&mut self, step: usize) {
-        let i = match self.table_state.selected() {
-            Some(i) => std::cmp::min(i + step, self.shows.len() - 1),
-            None => 0,
-        };
-        self.table_state.select(Some(i));
-        self.scroll_state = self.scroll_state.position(i as u16);
+    /// Toggles visibility of the in-app log panel, resetting its scroll
+    /// position back to the newest entry each time it's opened.
+    pub fn toggle_log_panel(&mut self) {
+        self.show_log_panel = !self.show_log_panel;
+        if self.show_log_panel {
+            self.log_scroll_offset = 0;
+            self.log_scroll = self.log_scroll.position(0);
+        }
     }
 
-    pub fn prev(&mut self, step: usize) {
-        let i = match self.table_state.selected() {
-            Some(i) => std::cmp::max(i as i32 - step as i32, 0) as usize,
-            None => self.shows.len() - 1,
-        };
-        self.table_state.select(Some(i));
-        self.scroll_state = self.scroll_state.position(i as u16);
+    /// Enters incremental-search mode with an empty query.
+    pub fn begin_query(&mut self) {
+        self.mode = AppMode::Querying;
+        self.input = Input::default();
+        // `generation` must stay monotonic for the app's lifetime: resetting
+        // it back to 0 here would let a still-in-flight result from an
+        // earlier search match this new query's low generation in
+        // `poll_query_result` and clobber live results with stale ones
+        self.search.query = String::new();
+        self.search.dirty = false;
+        self.search.last_edit = None;
     }
 
-    pub fn season_next(&mut self, step: usize) {
-        let max = self.show_view.seasons.len() - 1;
-        let i = match self.show_view.season_table_state.selected() {
-            Some(i) => std::cmp::min(i + step, max),
-            None => 0,
-        };
-        self.show_view.season_table_state.select(Some(i));
+    /// Records a keystroke in the search box; the actual query is sent once
+    /// typing pauses for [`QUERY_DEBOUNCE`] (see `tick`).
+    pub fn note_query_edit(&mut self) {
+        self.search.query = self.input.value().to_string();
+        self.search.dirty = true;
+        self.search.last_edit = Some(Instant::now());
     }
 
-    pub fn season_prev(&mut self, step: usize) {
-        let i = match self.show_view.season_table_state.selected() {
-            Some(i) => std::cmp::max(i as i32 - step as i32, 0) as usize,
-            None => 0,
-        };
-        self.show_view.season_table_state.select(Some(i));
+    /// Runs the current query immediately, bypassing the debounce, and
+    /// returns to the main view to look at the results.
+    pub fn confirm_query(&mut self) {
+        self.dispatch_query();
+        self.mode = AppMode::MainView;
+        self.columns.focused = self.columns.search_column_index();
     }
 
-    /// Cycle the watch status of a currently-selected season (similar to toggle_watch_status)
-    pub fn toggle_season_watch_status(&mut self) -> eyre::Result<()> {
-        if let Some(i) = self.show_view.season_table_state.selected() {
-            let season = &mut self.show_view.seasons[i];
-            info!("Currently selected season: {:?}", season);
+    /// Leaves search mode without changing the last-dispatched results.
+    pub fn cancel_query(&mut self) {
+        self.mode = AppMode::MainView;
+        // drop any edit still waiting out the debounce so `tick` doesn't send it
+        self.search.dirty = false;
+    }
 
-            season.user_status = match season.user_status {
-                UserStatusSeason::Unfilled => UserStatusSeason::OnRelease,
-                UserStatusSeason::OnRelease => UserStatusSeason::OtherDate,
-                UserStatusSeason::OtherDate => UserStatusSeason::Unfilled,
-            };
+    /// Sends the current query to `DataManager` under a fresh generation, so
+    /// a result from any older, now-superseded query is ignored on arrival.
+    fn dispatch_query(&mut self) {
+        self.search.generation += 1;
+        self.search.dirty = false;
+        self.data_manager
+            .request_query(self.search.query.clone(), self.search.generation);
+    }
 
-            // Update database
-            t_db::update_season(season)?;
+    /// Queues an [`App::expand_selected`] call for the next time the main loop
+    /// can await it; `handle_key_events` is synchronous, but expanding a show
+    /// may need to hit the network.
+    pub fn request_expand_selected(&mut self) {
+        self.pending_expand = true;
+    }
+
+    /// Takes and clears the pending-expand flag set by
+    /// [`App::request_expand_selected`].
+    pub fn take_pending_expand(&mut self) -> bool {
+        std::mem::take(&mut self.pending_expand)
+    }
+
+    pub fn next(&mut self, step: usize) {
+        if self.show_log_panel {
+            self.scroll_log(step as isize);
+        } else {
+            self.columns.focused_mut().next(step);
         }
+    }
 
-        Ok(())
+    pub fn prev(&mut self, step: usize) {
+        if self.show_log_panel {
+            self.scroll_log(-(step as isize));
+        } else {
+            self.columns.focused_mut().prev(step);
+        }
     }
 
-    /// Cycle watch status of a currently-selected show in main window
-    pub fn toggle_watch_status(&mut self) -> eyre::Result<()> {
-        if let Some(i) = self.table_state.selected() {
-            let show = &mut self.shows[i];
-            info!("Currently selected show: {:?}", show);
+    /// Moves the log panel's scroll position by `delta` lines, clamped to the
+    /// number of buffered lines.
+    fn scroll_log(&mut self, delta: isize) {
+        let max = self.log_buffer.lines().len().saturating_sub(1);
+        self.log_scroll_offset = (self.log_scroll_offset as isize + delta)
+            .clamp(0, max as isize) as usize;
+        self.log_scroll = self
+            .log_scroll
+            .content_length(max as u16)
+            .position(self.log_scroll_offset as u16);
+    }
 
-            show.user_status = match show.user_status {
-                UserStatusShow::Todo => UserStatusShow::Watched,
-                UserStatusShow::Watched => UserStatusShow::Unwatched,
-                UserStatusShow::Unwatched => UserStatusShow::Todo,
-            };
+    /// The show's current DB revision, used to decide whether a cached detail
+    /// fetch is still valid.
+    fn current_revision(&self, imdb_id: &str) -> u64 {
+        self.show_revisions.get(imdb_id).copied().unwrap_or(0)
+    }
+
+    /// Bumps `imdb_id`'s revision, invalidating any cached detail for it.
+    fn bump_revision(&mut self, imdb_id: String) {
+        *self.show_revisions.entry(imdb_id).or_insert(0) += 1;
+    }
 
-            // update db
+    /// Looks up `imdb_id` in `all_shows`, lets `mutate` change it, and persists
+    /// the result via `t_db::update_show`. Every `t_db::update_show` write goes
+    /// through here, and it always bumps the revision, so none of those callers
+    /// can forget to and silently leave a cached detail stale. Season writes
+    /// (`t_db::update_season` in `toggle_status`) bump the revision separately.
+    fn update_show_and_bump(
+        &mut self,
+        imdb_id: &str,
+        mutate: impl FnOnce(&mut TraktShow),
+    ) -> eyre::Result<()> {
+        if let Some(show) = self.all_shows.iter_mut().find(|s| s.imdb_id == imdb_id) {
+            mutate(show);
             t_db::update_show(show)?;
         }
-
+        self.bump_revision(imdb_id.to_string());
         Ok(())
     }
 
-    pub async fn enter_show_details(&mut self) -> eyre::Result<()> {
-        // when a user attempts to view details for a show, we query its details and season info
-        // and write back to local
-        if self.mode == AppMode::MainView && let Some(i) = self.table_state.selected() {
-            let show = &mut self.shows[i];
-            match t_api::query_detailed(&self.client, &show.imdb_id).await {
-                Ok((show_details, api_seasons)) => {
-                    // update a show's overview
-                    show.overview = Some(show_details.overview.clone());
-                    show.network = Some(show_details.network.clone());
-                    show.no_episodes = Some(show_details.aired_episodes as i32);
-
-                    // update a show's trakt_id in the db if show.trakt_id is currently None
-                    if show.trakt_id == None {
-                        show.trakt_id = Some(show_details.ids.trakt as i32);
-                        // let _ = t_db::update_show(show);
-                    }
-                    let _ = t_db::update_show(&show);
+    /// Cycles the watch status of the selected tree node in the focused column,
+    /// dispatching on whether it's a show or a season. Unifies the old separate
+    /// `toggle_watch_status`/`toggle_season_watch_status` actions.
+    pub fn toggle_status(&mut self) -> eyre::Result<()> {
+        let Some(i) = self.columns.focused().tree.selected else {
+            return Ok(());
+        };
 
-                    // insert the seasons of a show
-                    self.show_view.seasons = t_db::update_show_with_seasons(show, &api_seasons)?;
+        // a season's imdb id has to be read off its parent show node, so grab
+        // it up front to avoid overlapping with the mutable borrow below
+        let ancestor_imdb_id = self
+            .columns
+            .focused()
+            .tree
+            .ancestor_show_imdb_id(i)
+            .map(str::to_string);
+
+        enum Mutated {
+            Show { imdb_id: String, new_status: UserStatusShow },
+            Season,
+        }
 
-                    if !api_seasons.is_empty() {
-                        self.show_view.season_table_state.select(Some(0));
-                    }
+        let mutated = match &mut self.columns.focused_mut().tree.nodes[i].kind {
+            TreeNodeKind::Show(show) => {
+                info!("Currently selected show: {:?}", show);
+
+                show.user_status = match show.user_status {
+                    UserStatusShow::Todo => UserStatusShow::Watched,
+                    UserStatusShow::Watched => UserStatusShow::Unwatched,
+                    UserStatusShow::Unwatched => UserStatusShow::Todo,
+                };
+
+                Mutated::Show {
+                    imdb_id: show.imdb_id.clone(),
+                    new_status: show.user_status,
+                }
+            }
+            TreeNodeKind::Season(season) => {
+                info!("Currently selected season: {:?}", season);
+
+                season.user_status = match season.user_status {
+                    UserStatusSeason::Unfilled => UserStatusSeason::OnRelease,
+                    UserStatusSeason::OnRelease => UserStatusSeason::OtherDate,
+                    UserStatusSeason::OtherDate => UserStatusSeason::Unfilled,
+                };
+
+                t_db::update_season(season)?;
+
+                Mutated::Season
+            }
+        };
 
-                    self.mode = AppMode::SeasonView;
+        match mutated {
+            // routed through the shared choke point so this write bumps the
+            // revision the same way every other show mutation does
+            Mutated::Show { imdb_id, new_status } => {
+                self.update_show_and_bump(&imdb_id, |show| show.user_status = new_status)?;
+            }
+            Mutated::Season => {
+                if let Some(imdb_id) = ancestor_imdb_id {
+                    self.bump_revision(imdb_id);
                 }
-                Err(other) => {
-                    error!("error querying show details: {}", other);
-                    self.quit();
-                    eyre::bail!(other);
+            }
+        }
+
+        // the show may now belong to a different column (or none at all)
+        self.columns.refresh_all(&self.all_shows);
+
+        Ok(())
+    }
+
+    /// Expands or collapses the selected node. Expanding a show with no loaded
+    /// children serves its seasons from the detail cache when the cached entry's
+    /// revision is still current, and otherwise fetches them via
+    /// `t_api::query_detailed`; subsequent toggles just flip visibility of the
+    /// already-loaded children.
+    pub async fn expand_selected(&mut self) -> eyre::Result<()> {
+        let Some(i) = self.columns.focused().tree.selected else {
+            return Ok(());
+        };
+        let node = self
+            .columns
+            .focused()
+            .tree
+            .selected_node()
+            .expect("selected index always points at a node");
+
+        let needs_fetch = !node.expanded
+            && !self.columns.focused().tree.has_children(i)
+            && matches!(node.kind, TreeNodeKind::Show(_));
+
+        if needs_fetch {
+            let imdb_id = match &self.columns.focused().tree.nodes[i].kind {
+                TreeNodeKind::Show(show) => show.imdb_id.clone(),
+                TreeNodeKind::Season(_) => unreachable!("checked above"),
+            };
+
+            // a cache hit (same revision the entry was fetched at) skips the
+            // network round-trip entirely
+            let cached = self
+                .detail_cache
+                .get(&imdb_id)
+                .filter(|entry| entry.revision == self.current_revision(&imdb_id))
+                .cloned();
+
+            let seasons = match cached {
+                Some(detail) => {
+                    // cache hit: refresh the in-memory fields only. No DB
+                    // write happens here, so this read-only path can never
+                    // bump the revision and invalidate the entry it just served
+                    if let Some(show) = self.all_shows.iter_mut().find(|s| s.imdb_id == imdb_id) {
+                        show.overview = Some(detail.overview.clone());
+                        show.network = Some(detail.network.clone());
+                        show.no_episodes = Some(detail.aired_episodes);
+                        if show.trakt_id == None {
+                            show.trakt_id = Some(detail.trakt_id);
+                        }
+                    }
+                    detail.api_seasons.clone()
                 }
+                None => match t_api::query_detailed(&self.client, &imdb_id).await {
+                    Ok((show_details, api_seasons)) => {
+                        // routed through the shared choke point so this write
+                        // bumps the revision the same way every other show
+                        // mutation does, instead of skipping it by convention
+                        self.update_show_and_bump(&imdb_id, |show| {
+                            show.overview = Some(show_details.overview.clone());
+                            show.network = Some(show_details.network.clone());
+                            show.no_episodes = Some(show_details.aired_episodes as i32);
+                            if show.trakt_id == None {
+                                show.trakt_id = Some(show_details.ids.trakt as i32);
+                            }
+                        })?;
+
+                        let seasons = match self
+                            .all_shows
+                            .iter_mut()
+                            .find(|s| s.imdb_id == imdb_id)
+                        {
+                            Some(show) => t_db::update_show_with_seasons(show, &api_seasons)?,
+                            None => Vec::new(),
+                        };
+
+                        self.detail_cache.insert(
+                            imdb_id.clone(),
+                            CachedDetail {
+                                revision: self.current_revision(&imdb_id),
+                                overview: show_details.overview.clone(),
+                                network: show_details.network.clone(),
+                                aired_episodes: show_details.aired_episodes as i32,
+                                trakt_id: show_details.ids.trakt as i32,
+                                api_seasons: api_seasons.clone(),
+                            },
+                        );
+
+                        seasons
+                    }
+                    Err(other) => {
+                        // surface the failure in the log panel rather than quitting;
+                        // transient Trakt errors shouldn't tear down the TUI
+                        error!("error querying show details: {}", other);
+                        self.mode = AppMode::MainView;
+                        return Ok(());
+                    }
+                },
+            };
+
+            // the fetch may have taken a while; re-resolve the node by imdb id
+            // in case the tree was rebuilt out from under us
+            let node_index = self
+                .columns
+                .focused()
+                .tree
+                .nodes
+                .iter()
+                .position(|node| matches!(&node.kind, TreeNodeKind::Show(s) if s.imdb_id == imdb_id));
+
+            if let Some(i) = node_index {
+                self.columns.focused_mut().tree.set_children(i, seasons);
             }
         }
 
+        self.columns.focused_mut().tree.toggle_expand(i);
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn show(imdb_id: &str, user_status: UserStatusShow) -> TraktShow {
+        TraktShow {
+            imdb_id: imdb_id.to_string(),
+            user_status,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn move_down_and_up_stay_within_bounds() {
+        let mut tree = AppTree::from_shows(vec![
+            show("tt1", UserStatusShow::Todo),
+            show("tt2", UserStatusShow::Todo),
+            show("tt3", UserStatusShow::Todo),
+        ]);
+
+        assert_eq!(tree.selected, Some(0));
+
+        tree.move_down(1);
+        assert_eq!(tree.selected, Some(1));
+
+        tree.move_down(usize::MAX);
+        assert_eq!(tree.selected, Some(2));
+
+        tree.move_up(1);
+        assert_eq!(tree.selected, Some(1));
+
+        tree.move_up(usize::MAX);
+        assert_eq!(tree.selected, Some(0));
+    }
+
+    #[test]
+    fn toggle_expand_hides_and_reveals_children() {
+        let mut tree = AppTree::from_shows(vec![show("tt1", UserStatusShow::Todo)]);
+        // newly loaded children start visible; the real call path immediately
+        // follows `set_children` with `toggle_expand` to establish that state
+        tree.set_children(0, vec![TraktSeason::default(), TraktSeason::default()]);
+        assert!(!tree.nodes[0].expanded);
+
+        tree.toggle_expand(0);
+        assert!(tree.nodes[0].expanded);
+        assert!(tree.nodes[1].visible);
+        assert!(tree.nodes[2].visible);
+
+        tree.toggle_expand(0);
+        assert!(!tree.nodes[0].expanded);
+        assert!(!tree.nodes[1].visible);
+        assert!(!tree.nodes[2].visible);
+    }
+
+    #[test]
+    fn ancestor_show_imdb_id_resolves_through_season_nodes() {
+        let mut tree = AppTree::from_shows(vec![show("tt1", UserStatusShow::Todo)]);
+        tree.set_children(0, vec![TraktSeason::default()]);
+
+        assert_eq!(tree.ancestor_show_imdb_id(1), Some("tt1"));
+        assert_eq!(tree.ancestor_show_imdb_id(0), None);
+    }
+
+    #[test]
+    fn column_filter_matches_status_and_search() {
+        let todo = show("tt1", UserStatusShow::Todo);
+        let watched = show("tt2", UserStatusShow::Watched);
+
+        assert!(ColumnFilter::Todo.matches(&todo));
+        assert!(!ColumnFilter::Todo.matches(&watched));
+        assert!(ColumnFilter::Watched.matches(&watched));
+
+        assert!(ColumnFilter::Search("TT1".to_string()).matches(&todo));
+        assert!(!ColumnFilter::Search("tt1".to_string()).matches(&watched));
+    }
+}
@@ -1,77 +1,54 @@
-use crate::interface::app::{App, AppResult, InputMode};
-use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
+use crate::interface::app::{App, AppMode, AppResult};
+use crate::interface::keymap::{Action, KeyChord};
+use crossterm::event::{Event as CrosstermEvent, KeyEvent};
 use crossterm::event::{MouseEvent, MouseEventKind};
 
+use log::error;
 use tui_input::backend::crossterm::EventHandler;
 
 /// Handles the key events and updates the state of [`App`].
 pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
-    match app.mode {
-        InputMode::Normal => match key_event.code {
-            KeyCode::Tab => {
-                app.mode = InputMode::Editing;
-            }
-            _ => {}
-        },
-        InputMode::Editing => {
-            match key_event.code {
-                KeyCode::Enter => {
-                    // TODO: do something else here? query rows for entered text?
-                    app.mode = InputMode::Normal;
-                }
-                KeyCode::Tab => {
-                    app.mode = InputMode::Normal;
-                }
-                KeyCode::Esc => {
-                    app.mode = InputMode::Normal;
-                }
-                _ => {
-                    app.input.handle_event(&CrosstermEvent::Key(key_event));
-                }
-            };
+    let chord = KeyChord::from(key_event);
 
-            // exit early (until i rework handler logic?)
-            return Ok(());
+    match app.keymap.resolve(&app.mode, chord) {
+        Some(action) => dispatch(action, app),
+        None => {
+            // no binding for this chord in the active mode; while querying,
+            // let it fall through to the search box as free-text input
+            if app.mode == AppMode::Querying {
+                app.input.handle_event(&CrosstermEvent::Key(key_event));
+                app.note_query_edit();
+            }
         }
     }
 
-    match key_event.code {
-        // Exit application on `ESC` or `q`
-        KeyCode::Esc | KeyCode::Char('q') => {
-            app.quit();
-        }
-        // Exit application on `Ctrl-C`
-        KeyCode::Char('c') | KeyCode::Char('C') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
-                app.quit();
-            }
-        }
-        KeyCode::Up => {
-            app.prev(1);
-        }
-        KeyCode::Down => {
-            app.next(1);
-        }
-        KeyCode::Char('u') | KeyCode::Char('U') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
-                app.prev(20);
-            }
-        }
-        KeyCode::Char('d') | KeyCode::Char('D') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
-                app.next(20);
+    Ok(())
+}
+
+/// Runs the effect bound to `action`.
+fn dispatch(action: Action, app: &mut App) {
+    match action {
+        Action::Quit => app.quit(),
+        Action::Up => app.prev(1),
+        Action::Down => app.next(1),
+        Action::PageUp => app.prev(20),
+        Action::PageDown => app.next(20),
+        Action::Top => app.columns.focused_mut().tree.move_up(usize::MAX),
+        Action::Bottom => app.columns.focused_mut().tree.move_down(usize::MAX),
+        Action::FocusNextColumn => app.columns.focus_next(),
+        Action::FocusPrevColumn => app.columns.focus_prev(),
+        Action::ToggleStatus => {
+            if let Err(err) = app.toggle_status() {
+                error!("failed to toggle status: {}", err);
             }
         }
-        KeyCode::Char('g') => {
-            app.table_state.select(Some(0));
-        }
-        KeyCode::Char('G') => {
-            app.table_state.select(Some(app.items.len() - 1));
-        }
-        // Other handlers you could add here.
-        _ => {}
+        // expanding may need a network fetch; queued for the main loop to await
+        Action::EnterDetails => app.request_expand_selected(),
+        Action::ToggleLogPanel => app.toggle_log_panel(),
+        Action::BeginQuery => app.begin_query(),
+        Action::ConfirmQuery => app.confirm_query(),
+        Action::CancelQuery => app.cancel_query(),
     }
-    Ok(())
 }
 
 // handle mouse events as well
@@ -79,22 +56,15 @@ pub fn handle_mouse_events(mouse_event: MouseEvent, app: &mut App) -> AppResult<
     match mouse_event.kind {
         MouseEventKind::ScrollDown => {
             app.next(1);
-            app.mode = InputMode::Normal;
         }
         MouseEventKind::ScrollUp => {
             app.prev(1);
-            app.mode = InputMode::Normal;
         }
-        // TODO: select a show if clicked
+        // TODO: select a show if clicked, and focus the column under the cursor
         MouseEventKind::Down(_) => {
             let _col = mouse_event.column;
-            let row = mouse_event.row;
-
-            if row == 0 {
-                app.mode = InputMode::Editing;
-            } else if row > 1 {
-                // ... how do you get offset from table_state?
-            }
+            let _row = mouse_event.row;
+            // ... how do you map a row to a node of the focused column's tree?
         }
         _ => {}
     }
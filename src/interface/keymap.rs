@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use directories::ProjectDirs;
+use serde::Deserialize;
+
+use crate::interface::app::AppMode;
+
+/// Named, rebindable actions a key chord can be mapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum Action {
+    Quit,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    FocusNextColumn,
+    FocusPrevColumn,
+    ToggleStatus,
+    EnterDetails,
+    ToggleLogPanel,
+    BeginQuery,
+    ConfirmQuery,
+    CancelQuery,
+}
+
+/// A key press plus modifiers: the unit a [`Keymap`] binds to an [`Action`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(event: KeyEvent) -> Self {
+        KeyChord {
+            code: event.code,
+            modifiers: normalize_shift(event.code, event.modifiers),
+        }
+    }
+}
+
+/// Strips `SHIFT` from `modifiers` when `code` is a `Char`: crossterm reports
+/// shifted letters (e.g. `G`) as that already-cased `Char` plus `SHIFT` set,
+/// so leaving the bit in place would require every binding on a letter to be
+/// registered twice (once per case) instead of matching on the char alone.
+fn normalize_shift(code: KeyCode, modifiers: KeyModifiers) -> KeyModifiers {
+    match code {
+        KeyCode::Char(_) => modifiers - KeyModifiers::SHIFT,
+        _ => modifiers,
+    }
+}
+
+/// On-disk representation of a keymap config file: per-mode tables of
+/// `"chord" = "Action"` bindings, parsed with serde before being compiled into
+/// the lookup tables [`Keymap`] actually resolves against.
+#[derive(Debug, Deserialize, Default)]
+struct KeymapFile {
+    #[serde(default)]
+    main_view: HashMap<String, Action>,
+    #[serde(default)]
+    help_window: HashMap<String, Action>,
+    #[serde(default)]
+    querying: HashMap<String, Action>,
+}
+
+/// Per-[`AppMode`] key-chord -> action tables, resolved from the user's config
+/// file (or the built-in defaults for any mode/chord it doesn't override).
+#[derive(Debug)]
+pub struct Keymap {
+    tables: HashMap<AppMode, HashMap<KeyChord, Action>>,
+}
+
+impl Keymap {
+    /// Loads the user's keymap config via `directories`, falling back to
+    /// [`Keymap::default_map`] when no config file exists or it fails to parse.
+    pub fn load() -> Self {
+        let mut keymap = Self::default_map();
+
+        let Some(dirs) = ProjectDirs::from("", "", "trakt-tv-updater") else {
+            return keymap;
+        };
+        let path = dirs.config_dir().join("keymap.toml");
+
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return keymap;
+        };
+
+        match toml::from_str::<KeymapFile>(&contents) {
+            Ok(file) => {
+                keymap.apply_overrides(AppMode::MainView, file.main_view);
+                keymap.apply_overrides(AppMode::HelpWindow, file.help_window);
+                keymap.apply_overrides(AppMode::Querying, file.querying);
+            }
+            Err(err) => {
+                log::warn!("failed to parse keymap at {}: {}", path.display(), err);
+            }
+        }
+
+        keymap
+    }
+
+    fn apply_overrides(&mut self, mode: AppMode, overrides: HashMap<String, Action>) {
+        let table = self.tables.entry(mode).or_default();
+        for (chord_str, action) in overrides {
+            match parse_chord(&chord_str) {
+                Some(chord) => {
+                    table.insert(chord, action);
+                }
+                None => log::warn!("unrecognized key chord in keymap: {}", chord_str),
+            }
+        }
+    }
+
+    /// Resolves `chord` against `mode`'s table, returning the bound action, if any.
+    pub fn resolve(&self, mode: &AppMode, chord: KeyChord) -> Option<Action> {
+        self.tables.get(mode)?.get(&chord).copied()
+    }
+
+    /// The built-in bindings used when no config file overrides them.
+    fn default_map() -> Self {
+        let mut main_view = HashMap::new();
+        main_view.insert(chord(KeyCode::Esc, KeyModifiers::NONE), Action::Quit);
+        main_view.insert(chord(KeyCode::Char('q'), KeyModifiers::NONE), Action::Quit);
+        main_view.insert(chord(KeyCode::Char('c'), KeyModifiers::CONTROL), Action::Quit);
+        main_view.insert(chord(KeyCode::Up, KeyModifiers::NONE), Action::Up);
+        main_view.insert(chord(KeyCode::Down, KeyModifiers::NONE), Action::Down);
+        main_view.insert(chord(KeyCode::Char('u'), KeyModifiers::CONTROL), Action::PageUp);
+        main_view.insert(chord(KeyCode::Char('d'), KeyModifiers::CONTROL), Action::PageDown);
+        main_view.insert(chord(KeyCode::Char('g'), KeyModifiers::NONE), Action::Top);
+        main_view.insert(chord(KeyCode::Char('G'), KeyModifiers::NONE), Action::Bottom);
+        main_view.insert(chord(KeyCode::Tab, KeyModifiers::NONE), Action::FocusNextColumn);
+        main_view.insert(chord(KeyCode::BackTab, KeyModifiers::NONE), Action::FocusPrevColumn);
+        main_view.insert(chord(KeyCode::Char(' '), KeyModifiers::NONE), Action::ToggleStatus);
+        main_view.insert(chord(KeyCode::Enter, KeyModifiers::NONE), Action::EnterDetails);
+        main_view.insert(chord(KeyCode::Char('l'), KeyModifiers::CONTROL), Action::ToggleLogPanel);
+        main_view.insert(chord(KeyCode::Char('/'), KeyModifiers::NONE), Action::BeginQuery);
+
+        let mut querying = HashMap::new();
+        querying.insert(chord(KeyCode::Enter, KeyModifiers::NONE), Action::ConfirmQuery);
+        querying.insert(chord(KeyCode::Esc, KeyModifiers::NONE), Action::CancelQuery);
+        // keep the baseline's global abort available while typing a search
+        querying.insert(chord(KeyCode::Char('c'), KeyModifiers::CONTROL), Action::Quit);
+
+        let mut tables = HashMap::new();
+        tables.insert(AppMode::MainView, main_view);
+        tables.insert(AppMode::Querying, querying);
+
+        Keymap { tables }
+    }
+}
+
+fn chord(code: KeyCode, modifiers: KeyModifiers) -> KeyChord {
+    KeyChord { code, modifiers }
+}
+
+/// Parses a chord string like `"ctrl+d"` or `"G"` into a [`KeyChord`].
+fn parse_chord(s: &str) -> Option<KeyChord> {
+    let mut parts: Vec<&str> = s.split('+').collect();
+    let key_part = parts.pop()?;
+
+    let mut modifiers = KeyModifiers::NONE;
+    for part in parts {
+        modifiers |= match part.to_lowercase().as_str() {
+            "ctrl" => KeyModifiers::CONTROL,
+            "shift" => KeyModifiers::SHIFT,
+            "alt" => KeyModifiers::ALT,
+            _ => return None,
+        };
+    }
+
+    let mut code = match key_part {
+        s if s.eq_ignore_ascii_case("esc") => KeyCode::Esc,
+        s if s.eq_ignore_ascii_case("enter") => KeyCode::Enter,
+        s if s.eq_ignore_ascii_case("tab") => KeyCode::Tab,
+        s if s.eq_ignore_ascii_case("backtab") => KeyCode::BackTab,
+        s if s.eq_ignore_ascii_case("up") => KeyCode::Up,
+        s if s.eq_ignore_ascii_case("down") => KeyCode::Down,
+        s if s.eq_ignore_ascii_case("space") => KeyCode::Char(' '),
+        s if s.chars().count() == 1 => KeyCode::Char(s.chars().next().unwrap()),
+        _ => return None,
+    };
+
+    // letters encode case directly (a Shift+G press arrives as `Char('G')`,
+    // and `normalize_shift` strips its SHIFT bit); fold an explicit
+    // `shift+<letter>` config chord to the uppercase char the same way, so a
+    // config-file binding and the runtime event it's meant to match agree
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        if let KeyCode::Char(c) = code {
+            if c.is_alphabetic() {
+                code = KeyCode::Char(c.to_ascii_uppercase());
+            }
+        }
+    }
+
+    Some(KeyChord {
+        code,
+        modifiers: normalize_shift(code, modifiers),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plain_and_modified_chords() {
+        assert_eq!(
+            parse_chord("G"),
+            Some(chord(KeyCode::Char('G'), KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_chord("ctrl+d"),
+            Some(chord(KeyCode::Char('d'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_chord("esc"),
+            Some(chord(KeyCode::Esc, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_chord("space"),
+            Some(chord(KeyCode::Char(' '), KeyModifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn folds_shift_letter_chords_to_the_uppercase_char() {
+        // "shift+g" and "G" both describe the same keystroke: the event side
+        // normalizes a real Shift+G press to `Char('G')` with no SHIFT bit,
+        // so a config chord has to fold to the same uppercase char to match
+        assert_eq!(parse_chord("shift+g"), parse_chord("G"));
+        assert_ne!(parse_chord("shift+g"), parse_chord("g"));
+    }
+
+    #[test]
+    fn rejects_unknown_chords() {
+        assert_eq!(parse_chord("nope+g"), None);
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use log::{Level, Log, Metadata, Record, SetLoggerError};
+
+/// Maximum number of formatted log lines retained for the in-app log panel.
+const MAX_LINES: usize = 500;
+
+/// Shared ring buffer of formatted log lines, rendered by the in-app log panel.
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer(Arc<Mutex<VecDeque<String>>>);
+
+impl LogBuffer {
+    pub fn lines(&self) -> Vec<String> {
+        self.0
+            .lock()
+            .expect("log buffer poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    fn push(&self, line: String) {
+        let mut lines = self.0.lock().expect("log buffer poisoned");
+        if lines.len() >= MAX_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+/// `log::Log` impl that formats records into a [`LogBuffer`] instead of stdout/
+/// stderr, so API failures surface as scrollable, dismissable panel entries
+/// instead of tearing down the TUI.
+struct BufferLogger {
+    buffer: LogBuffer,
+}
+
+impl Log for BufferLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Info
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        self.buffer
+            .push(format!("[{}] {}", record.level(), record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs `buffer` as the process-wide `log` subscriber. Must be called
+/// exactly once, before the TUI takes over the terminal.
+pub fn install(buffer: LogBuffer) -> Result<(), SetLoggerError> {
+    log::set_boxed_logger(Box::new(BufferLogger { buffer }))?;
+    log::set_max_level(log::LevelFilter::Info);
+    Ok(())
+}
@@ -13,6 +13,12 @@ mod tui;
 /// Event handler.
 mod handler;
 
+/// In-app log panel buffer.
+mod log_buffer;
+
+/// Configurable, per-mode key bindings.
+mod keymap;
+
 use crate::{
     interface::{
         app::{App, AppResult},
@@ -26,7 +32,7 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use std::io;
 
-pub fn run(items: Vec<TraktShow>) -> AppResult<()> {
+pub async fn run(items: Vec<TraktShow>) -> AppResult<()> {
     // Create an application.
     let mut app = App::new(items);
 
@@ -44,7 +50,15 @@ pub fn run(items: Vec<TraktShow>) -> AppResult<()> {
         // Handle events.
         match tui.events.next()? {
             Event::Tick => app.tick(),
-            Event::Key(key_event) => handle_key_events(key_event, &mut app)?,
+            Event::Key(key_event) => {
+                handle_key_events(key_event, &mut app)?;
+
+                // expanding a tree node may need a network fetch, so
+                // handle_key_events only flags it; drive it here where we can await
+                if app.take_pending_expand() {
+                    app.expand_selected().await?;
+                }
+            }
             Event::Mouse(mouse_event) => handle_mouse_events(mouse_event, &mut app)?,
             Event::Resize(_, _) => {}
         }